@@ -0,0 +1,48 @@
+use super::{Api, ListParams, Meta, WatchEvent};
+use crate::Result;
+use http::header::{HeaderValue, ACCEPT};
+use k8s_openapi::{apimachinery::pkg::apis::meta::v1::ObjectMeta, List};
+use serde::de::DeserializeOwned;
+
+/// `PartialObjectMetadata*` accept headers understood by the apiserver
+///
+/// Requesting these instead of the normal `application/json` means the response body
+/// never contains `spec`/`status` for any `K`, no matter how large it is.
+const ACCEPT_METADATA_LIST: &str =
+    "application/json;as=PartialObjectMetadataList;g=meta.k8s.io;v=v1";
+const ACCEPT_METADATA_WATCH: &str = "application/json;as=PartialObjectMetadata;g=meta.k8s.io;v=v1";
+
+impl<K> Api<K>
+where
+    K: Clone + DeserializeOwned + Meta,
+{
+    /// List only the metadata of objects matching `lp`
+    ///
+    /// Used by [`crate::runtime::reflector::MetaReflector`] to keep a cache of
+    /// [`ObjectMeta`] rather than full `K` objects.
+    pub fn get_metadata(&self, lp: &ListParams) -> Result<List<ObjectMeta>> {
+        let mut req = self.resource.list(lp)?;
+        req.headers_mut()
+            .insert(ACCEPT, HeaderValue::from_static(ACCEPT_METADATA_LIST));
+        self.client.request::<List<ObjectMeta>>(req)
+    }
+
+    /// Watch only the metadata of objects matching `lp`, from the given resourceVersion
+    ///
+    /// As [`Api::get_metadata`], but for the watch endpoint.
+    ///
+    /// This assumes `Client` exposes a `request_events<T>` that mirrors `Client::request<T>`
+    /// but deserializes a stream of `WatchEvent<T>`, the same way [`Api::watch`] already
+    /// does for full `K` objects - if the real client names this differently, update the
+    /// call below to match.
+    pub fn watch_metadata(
+        &self,
+        lp: &ListParams,
+        version: &str,
+    ) -> Result<impl Iterator<Item = Result<WatchEvent<ObjectMeta>>>> {
+        let mut req = self.resource.watch(lp, version)?;
+        req.headers_mut()
+            .insert(ACCEPT, HeaderValue::from_static(ACCEPT_METADATA_WATCH));
+        self.client.request_events::<WatchEvent<ObjectMeta>>(req)
+    }
+}
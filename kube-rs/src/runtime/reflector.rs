@@ -2,9 +2,20 @@ use crate::{
     api::{Api, ListParams, Meta, WatchEvent},
     Error, Result,
 };
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use serde::de::DeserializeOwned;
 
-use std::{collections::BTreeMap, sync::Arc, sync::Mutex};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    marker::PhantomData,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Condvar, Mutex,
+    },
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 /// A reflection of state for a Kubernetes ['Api'] resource
 ///
@@ -17,27 +28,77 @@ use std::{collections::BTreeMap, sync::Arc, sync::Mutex};
 /// reset (boot equivalent) when network issues are encountered.
 /// During a reset, the state is cleared before it is rebuilt.
 ///
-/// The internal state is exposed readably through a getter.
-#[derive(Clone)]
-pub struct Reflector<K>
+/// The internal state is exposed readably through a getter. What actually gets
+/// cached for each object is decided by the reflector's [`Store`] - by default a
+/// [`BTreeMapStore`] that caches full `K` objects, but this can be swapped out for
+/// e.g. a [`ShadowStore`] that only retains a cheap derived projection of `K`.
+pub struct Reflector<K, S = BTreeMapStore<K>>
 where
     K: Clone + DeserializeOwned + Meta,
+    S: Store<K>,
 {
-    state: Arc<Mutex<State<K>>>,
+    version: Arc<Mutex<String>>,
+    store: Arc<S>,
+    subscribers: Arc<Mutex<Vec<Sender<StoreEvent<S::Output>>>>>,
+    backoff: Backoff,
+    attempt: Arc<Mutex<u32>>,
+    ready: Arc<(Mutex<bool>, Condvar)>,
     params: ListParams,
     api: Api<K>,
 }
 
-impl<K> Reflector<K>
+impl<K, S> Clone for Reflector<K, S>
+where
+    K: Clone + DeserializeOwned + Meta,
+    S: Store<K>,
+{
+    fn clone(&self) -> Self {
+        Reflector {
+            version: self.version.clone(),
+            store: self.store.clone(),
+            subscribers: self.subscribers.clone(),
+            backoff: self.backoff,
+            attempt: self.attempt.clone(),
+            ready: self.ready.clone(),
+            params: self.params.clone(),
+            api: self.api.clone(),
+        }
+    }
+}
+
+impl<K> Reflector<K, BTreeMapStore<K>>
 where
     K: Clone + DeserializeOwned + Meta,
 {
     /// Create a reflector on an api resource
+    ///
+    /// Caches full `K` objects in a [`BTreeMapStore`], preserving the reflector's
+    /// original behaviour. Use [`Reflector::new_with_store`] to plug in a different
+    /// [`Store`] implementation.
     pub fn new(api: Api<K>) -> Self {
+        Reflector::new_with_store(api, BTreeMapStore::default())
+    }
+}
+
+impl<K, S> Reflector<K, S>
+where
+    K: Clone + DeserializeOwned + Meta,
+    S: Store<K>,
+{
+    /// Create a reflector on an api resource backed by a custom [`Store`]
+    ///
+    /// This is the hook for projected/shadow caching: pass a [`ShadowStore`] to keep
+    /// only a cheap derived value per object instead of the full `K`.
+    pub fn new_with_store(api: Api<K>, store: S) -> Self {
         Reflector {
             api,
             params: ListParams::default(),
-            state: Default::default(),
+            version: Arc::new(Mutex::new(0.to_string())),
+            store: Arc::new(store),
+            subscribers: Default::default(),
+            backoff: Backoff::default(),
+            attempt: Default::default(),
+            ready: Default::default(),
         }
     }
 
@@ -47,64 +108,94 @@ where
         self
     }
 
+    /// Configure the exponential backoff used between failed [`Reflector::poll`] calls
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
     /// A single poll call to modify the internal state
     pub fn poll(&self) -> Result<()> {
         let kind = &self.api.resource.kind;
-        let resource_version = self.state.lock().unwrap().version.clone();
+        let resource_version = self.version.lock().unwrap().clone();
         trace!("Polling {} from resourceVersion={}", kind, resource_version);
         let events = self.api.watch(&self.params, &resource_version)?;
 
         for ev in events {
-            let mut state = self.state.lock().unwrap();
             // Informer-like version tracking:
             match &ev {
                 Ok(WatchEvent::Added(o))
                 | Ok(WatchEvent::Modified(o))
                 | Ok(WatchEvent::Deleted(o))
                 | Ok(WatchEvent::Bookmark(o)) => {
-                    // always store the last seen resourceVersion
                     if let Some(nv) = Meta::resource_ver(o) {
                         trace!("Updating reflector version for {} to {}", kind, nv);
-                        state.version = nv.clone();
+                        *self.version.lock().unwrap() = nv.clone();
                     }
                 }
                 _ => {}
             }
 
-            let data = &mut state.data;
             // Core Reflector logic
-            match ev {
-                Ok(WatchEvent::Added(o)) => {
-                    debug!("Adding {} to {}", Meta::name(&o), kind);
-                    data.entry(ObjectId::key_for(&o))
-                        .or_insert_with(|| o.clone());
-                }
-                Ok(WatchEvent::Modified(o)) => {
-                    debug!("Modifying {} in {}", Meta::name(&o), kind);
-                    data.entry(ObjectId::key_for(&o))
-                        .and_modify(|e| *e = o.clone());
-                }
-                Ok(WatchEvent::Deleted(o)) => {
-                    debug!("Removing {} from {}", Meta::name(&o), kind);
-                    data.remove(&ObjectId::key_for(&o));
-                }
+            match &ev {
+                Ok(WatchEvent::Added(o)) => debug!("Adding {} to {}", Meta::name(o), kind),
+                Ok(WatchEvent::Modified(o)) => debug!("Modifying {} in {}", Meta::name(o), kind),
+                Ok(WatchEvent::Deleted(o)) => debug!("Removing {} from {}", Meta::name(o), kind),
                 Ok(WatchEvent::Bookmark(o)) => {
-                    debug!("Bookmarking {} from {}", Meta::name(&o), kind);
+                    debug!("Bookmarking {} from {}", Meta::name(o), kind)
+                }
+                Ok(WatchEvent::Error(e)) => warn!("Failed to watch {}: {:?}", kind, e),
+                Err(e) => warn!("Received error while watcing {}: {:?}", kind, e),
+            }
+
+            match ev {
+                // A 410 Gone means our resourceVersion is too old (e.g. etcd compaction);
+                // the watch can never recover from it, so relist instead of erroring out.
+                // The failure itself was already warned about above; this is just the
+                // recovery action, so it doesn't need to warn again.
+                Ok(WatchEvent::Error(e)) if e.code == 410 => {
+                    debug!("{} relisting after 410 Gone", kind);
+                    self.reset()?;
+                    *self.attempt.lock().unwrap() = 0;
+                    return Ok(());
                 }
                 Ok(WatchEvent::Error(e)) => {
-                    warn!("Failed to watch {}: {:?}", kind, e);
+                    self.backoff_and_wait();
                     return Err(Error::Api(e));
                 }
                 Err(e) => {
-                    warn!("Received error while watcing {}: {:?}", kind, e);
+                    self.backoff_and_wait();
                     return Err(e);
                 }
+                Ok(ev) => {
+                    if let Some(change) = self.store.apply(&ev) {
+                        self.broadcast(change);
+                    }
+                }
             }
         }
 
+        *self.attempt.lock().unwrap() = 0;
         Ok(())
     }
 
+    /// Sleep for the next [`Backoff`] delay, and record the attempt for next time
+    fn backoff_and_wait(&self) {
+        let mut attempt = self.attempt.lock().unwrap();
+        let delay = self.backoff.delay_for(*attempt);
+        *attempt = attempt.saturating_add(1);
+        drop(attempt);
+
+        if !delay.is_zero() {
+            trace!(
+                "Backing off {} watch for {:?}",
+                self.api.resource.kind,
+                delay
+            );
+            thread::sleep(delay);
+        }
+    }
+
     /// Reset the state of the underlying informer and clear the cache
     pub fn reset(&self) -> Result<()> {
         trace!("Resetting {}", self.api.resource.kind);
@@ -113,16 +204,66 @@ where
         //self.informer.reset().await
 
         // For now:
-        let (data, version) = self.get_full_resource_entries()?;
-        *self.state.lock().unwrap() = State { data, version };
+        let (items, version) = self.get_full_resource_entries()?;
+        self.store.reset(items);
+        *self.version.lock().unwrap() = version;
+        self.broadcast(StoreEvent::Restarted(self.store.snapshot()));
+        self.mark_ready();
         Ok(())
     }
 
+    /// Block until the initial list has been applied and the cache is warm
+    ///
+    /// Controllers should call this before reconciling off [`Reflector::state`], so they
+    /// don't act on an empty cache and e.g. wrongly delete orphaned resources during
+    /// startup.
+    pub fn wait_ready(&self) {
+        let (ready, cvar) = &*self.ready;
+        let mut ready = ready.lock().unwrap();
+        while !*ready {
+            ready = cvar.wait(ready).unwrap();
+        }
+    }
+
+    /// Whether the initial list has been applied at least once
+    pub fn is_ready(&self) -> bool {
+        *self.ready.0.lock().unwrap()
+    }
+
+    fn mark_ready(&self) {
+        let (ready, cvar) = &*self.ready;
+        *ready.lock().unwrap() = true;
+        cvar.notify_all();
+    }
+
+    /// Subscribe to a stream of cache deltas as [`Reflector::poll`] applies them
+    ///
+    /// Emits an `Added`/`Modified`/`Deleted` [`StoreEvent`] for each watch event applied,
+    /// and a `Restarted` event whenever [`Reflector::reset`] rebuilds the cache from a
+    /// fresh list. Any number of subscriptions can be held at once; each receives every
+    /// change independently, so multiple controllers can drive off one shared reflector
+    /// without polling or cloning the whole cache.
+    ///
+    /// The channel backing a [`Subscription`] is unbounded, and a `Restarted` event clones
+    /// the entire snapshot into it on every [`Reflector::reset`]. A subscriber that stops
+    /// draining its [`Subscription`] (e.g. a stuck or slow controller) will grow that queue
+    /// without bound until it's dropped - make sure every subscription is actively consumed.
+    pub fn subscribe(&self) -> Subscription<S::Output> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        Subscription(rx)
+    }
+
+    fn broadcast(&self, event: StoreEvent<S::Output>) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
     /// Legacy helper for kubernetes < 1.16
     ///
     /// Needed to do an initial list operation because of https://github.com/clux/kube-rs/issues/219
     /// Soon, this goes away as we drop support for k8s < 1.16
-    fn get_full_resource_entries(&self) -> Result<(Cache<K>, String)> {
+    fn get_full_resource_entries(&self) -> Result<(Vec<K>, String)> {
         let res = self.api.list(&self.params)?;
         let version = res.metadata.resource_version.unwrap_or_default();
         trace!(
@@ -131,10 +272,240 @@ where
             self.api.resource.kind,
             version
         );
+        let keys = res
+            .items
+            .iter()
+            .map(|i| ObjectId::key_for(i).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        debug!("Initialized with: [{}]", keys);
+        Ok((res.items, version))
+    }
+
+    /// Read data for users of the reflector
+    ///
+    /// This is instant if you are reading and writing from the same context.
+    pub fn state(&self) -> Result<Vec<S::Output>> {
+        Ok(self.store.snapshot())
+    }
+
+    /// Read a single entry by name
+    ///
+    /// Will read in the configured namespace, or globally on non-namespaced reflectors.
+    /// If you are using a non-namespaced resources with name clashes,
+    /// Try [`Reflector::get_within`] instead.
+    pub fn get(&self, name: &str) -> Result<Option<S::Output>> {
+        let id = ObjectId {
+            name: name.into(),
+            namespace: self.api.resource.namespace.clone(),
+        };
+        Ok(self.store.get(&id))
+    }
+
+    /// Read a single entry by name within a specific namespace
+    ///
+    /// This is a more specific version of [`Reflector::get`].
+    /// This is only useful if your reflector is configured to poll across namespaces.
+    /// TODO: remove once #194 is resolved
+    pub fn get_within(&self, name: &str, ns: &str) -> Result<Option<S::Output>> {
+        let id = ObjectId {
+            name: name.into(),
+            namespace: Some(ns.into()),
+        };
+        Ok(self.store.get(&id))
+    }
+}
+
+/// Exponential backoff used by [`Reflector::poll`] between failed watch attempts
+///
+/// Keeps a long-running reflector from hot-looping against an unreachable or
+/// misbehaving apiserver. The delay for a given attempt is `base * 2^attempt`,
+/// capped at `cap`, with up to `jitter` (a fraction of the capped delay, `0.0..=1.0`)
+/// of random slack added on top.
+#[derive(Clone, Copy)]
+pub struct Backoff {
+    base: Duration,
+    cap: Duration,
+    jitter: f64,
+}
+
+impl Backoff {
+    /// Create a new backoff with the given base delay, cap, and jitter fraction
+    pub fn new(base: Duration, cap: Duration, jitter: f64) -> Self {
+        Backoff { base, cap, jitter }
+    }
+
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base
+            .as_millis()
+            .saturating_mul(1u128 << attempt.min(32));
+        let capped = exp.min(self.cap.as_millis());
+        let jittered = if self.jitter > 0.0 {
+            let nanos = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.subsec_nanos())
+                .unwrap_or(0);
+            let frac = f64::from(nanos % 1_000) / 1_000.0;
+            capped + (capped as f64 * self.jitter * frac) as u128
+        } else {
+            capped
+        };
+        Duration::from_millis(jittered.min(u128::from(u64::MAX)) as u64)
+    }
+}
+
+impl Default for Backoff {
+    /// 200ms base, 30s cap, 20% jitter
+    fn default() -> Self {
+        Backoff::new(Duration::from_millis(200), Duration::from_secs(30), 0.2)
+    }
+}
+
+/// A reflection of only the metadata for a Kubernetes ['Api'] resource
+///
+/// Parallel to [`Reflector`], but watches and caches [`ObjectMeta`] rather than full `K`
+/// objects, via the apiserver's `PartialObjectMetadata` representation (the
+/// `application/json;as=PartialObjectMetadataList` accept header on list, and the
+/// metadata watch on watch). This is for controllers that only need names, labels,
+/// annotations and owner references - e.g. building an owner -> children index over a
+/// high-cardinality resource - without paying to cache full object bodies.
+///
+/// Follows the same resourceVersion tracking as [`Reflector`], and is rebuilt from a
+/// fresh list by [`MetaReflector::reset`]. Unlike [`Reflector::poll`], its `poll`
+/// returns every watch error to the caller, a 410 Gone included, without relisting
+/// or backing off.
+pub struct MetaReflector<K>
+where
+    K: Clone + DeserializeOwned + Meta,
+{
+    version: Arc<Mutex<String>>,
+    data: Arc<Mutex<BTreeMap<ObjectId, ObjectMeta>>>,
+    params: ListParams,
+    api: Api<K>,
+}
+
+impl<K> Clone for MetaReflector<K>
+where
+    K: Clone + DeserializeOwned + Meta,
+{
+    fn clone(&self) -> Self {
+        MetaReflector {
+            version: self.version.clone(),
+            data: self.data.clone(),
+            params: self.params.clone(),
+            api: self.api.clone(),
+        }
+    }
+}
+
+impl<K> MetaReflector<K>
+where
+    K: Clone + DeserializeOwned + Meta,
+{
+    /// Create a metadata-only reflector on an api resource
+    pub fn new(api: Api<K>) -> Self {
+        MetaReflector {
+            api,
+            params: ListParams::default(),
+            version: Arc::new(Mutex::new(0.to_string())),
+            data: Default::default(),
+        }
+    }
+
+    /// Modify the default watch parameters for the underlying metadata watch
+    pub fn params(mut self, lp: ListParams) -> Self {
+        self.params = lp;
+        self
+    }
+
+    /// A single poll call to modify the internal state
+    pub fn poll(&self) -> Result<()> {
+        let kind = &self.api.resource.kind;
+        let resource_version = self.version.lock().unwrap().clone();
+        trace!(
+            "Polling {} metadata from resourceVersion={}",
+            kind,
+            resource_version
+        );
+        let events = self.api.watch_metadata(&self.params, &resource_version)?;
+
+        for ev in events {
+            // Informer-like version tracking:
+            match &ev {
+                Ok(WatchEvent::Added(o))
+                | Ok(WatchEvent::Modified(o))
+                | Ok(WatchEvent::Deleted(o))
+                | Ok(WatchEvent::Bookmark(o)) => {
+                    if let Some(nv) = &o.resource_version {
+                        trace!("Updating reflector version for {} to {}", kind, nv);
+                        *self.version.lock().unwrap() = nv.clone();
+                    }
+                }
+                _ => {}
+            }
+
+            let mut data = self.data.lock().unwrap();
+            // Core Reflector logic, operating on ObjectMeta instead of K
+            match ev {
+                Ok(WatchEvent::Added(o)) => {
+                    let id = ObjectId::from_meta(&o);
+                    debug!("Adding {} to {}", id, kind);
+                    data.entry(id).or_insert_with(|| o.clone());
+                }
+                Ok(WatchEvent::Modified(o)) => {
+                    let id = ObjectId::from_meta(&o);
+                    debug!("Modifying {} in {}", id, kind);
+                    data.entry(id).and_modify(|e| *e = o.clone());
+                }
+                Ok(WatchEvent::Deleted(o)) => {
+                    let id = ObjectId::from_meta(&o);
+                    debug!("Removing {} from {}", id, kind);
+                    data.remove(&id);
+                }
+                Ok(WatchEvent::Bookmark(o)) => {
+                    let id = ObjectId::from_meta(&o);
+                    debug!("Bookmarking {} from {}", id, kind);
+                }
+                Ok(WatchEvent::Error(e)) => {
+                    warn!("Failed to watch {} metadata: {:?}", kind, e);
+                    return Err(Error::Api(e));
+                }
+                Err(e) => {
+                    warn!("Received error while watcing {} metadata: {:?}", kind, e);
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reset the state of the underlying informer and clear the cache
+    pub fn reset(&self) -> Result<()> {
+        trace!("Resetting {} metadata", self.api.resource.kind);
+        let (data, version) = self.get_full_resource_entries()?;
+        *self.data.lock().unwrap() = data;
+        *self.version.lock().unwrap() = version;
+        Ok(())
+    }
+
+    /// Legacy helper for kubernetes < 1.16
+    ///
+    /// Needed to do an initial list operation because of https://github.com/clux/kube-rs/issues/219
+    /// Soon, this goes away as we drop support for k8s < 1.16
+    fn get_full_resource_entries(&self) -> Result<(BTreeMap<ObjectId, ObjectMeta>, String)> {
+        let res = self.api.get_metadata(&self.params)?;
+        let version = res.metadata.resource_version.unwrap_or_default();
+        trace!(
+            "Got {} {} metadata entries at resourceVersion={:?}",
+            res.items.len(),
+            self.api.resource.kind,
+            version
+        );
         let mut data = BTreeMap::new();
         for i in res.items {
-            // The non-generic parts we care about are spec + status
-            data.insert(ObjectId::key_for(&i), i);
+            data.insert(ObjectId::from_meta(&i), i);
         }
         let keys = data
             .keys()
@@ -148,36 +519,237 @@ where
     /// Read data for users of the reflector
     ///
     /// This is instant if you are reading and writing from the same context.
-    pub fn state(&self) -> Result<Vec<K>> {
-        let state = self.state.lock().unwrap();
-        Ok(state.data.values().cloned().collect::<Vec<K>>())
+    pub fn state(&self) -> Result<Vec<ObjectMeta>> {
+        Ok(self.data.lock().unwrap().values().cloned().collect())
     }
 
     /// Read a single entry by name
     ///
     /// Will read in the configured namespace, or globally on non-namespaced reflectors.
     /// If you are using a non-namespaced resources with name clashes,
-    /// Try [`Reflector::get_within`] instead.
-    pub fn get(&self, name: &str) -> Result<Option<K>> {
+    /// Try [`MetaReflector::get_within`] instead.
+    pub fn get(&self, name: &str) -> Result<Option<ObjectMeta>> {
         let id = ObjectId {
             name: name.into(),
             namespace: self.api.resource.namespace.clone(),
         };
-
-        Ok(self.state.lock().unwrap().data.get(&id).map(Clone::clone))
+        Ok(self.data.lock().unwrap().get(&id).cloned())
     }
 
     /// Read a single entry by name within a specific namespace
     ///
-    /// This is a more specific version of [`Reflector::get`].
+    /// This is a more specific version of [`MetaReflector::get`].
     /// This is only useful if your reflector is configured to poll across namespaces.
-    /// TODO: remove once #194 is resolved
-    pub fn get_within(&self, name: &str, ns: &str) -> Result<Option<K>> {
+    pub fn get_within(&self, name: &str, ns: &str) -> Result<Option<ObjectMeta>> {
         let id = ObjectId {
             name: name.into(),
             namespace: Some(ns.into()),
         };
-        Ok(self.state.lock().unwrap().data.get(&id).map(Clone::clone))
+        Ok(self.data.lock().unwrap().get(&id).cloned())
+    }
+}
+
+/// The pluggable cache backing a [`Reflector`]
+///
+/// Implementors decide what gets retained for each `K` seen on the watch stream.
+/// This is the extension point that lets a reflector keep something cheaper than
+/// the full object in memory - see [`ShadowStore`].
+pub trait Store<K>: Send + Sync
+where
+    K: Meta,
+{
+    /// The type handed back to callers of the reflector - `K` itself, or a projection of it
+    type Output: Clone;
+
+    /// Apply a single watch event to the store, returning the change it caused, if any
+    fn apply(&self, event: &WatchEvent<K>) -> Option<StoreEvent<Self::Output>>;
+
+    /// Replace the entire contents of the store, as done by [`Reflector::reset`]
+    fn reset(&self, items: Vec<K>);
+
+    /// Look up a single entry by id
+    fn get(&self, id: &ObjectId) -> Option<Self::Output>;
+
+    /// Take a snapshot of every entry currently cached
+    fn snapshot(&self) -> Vec<Self::Output>;
+}
+
+/// The default [`Store`] used by [`Reflector::new`]
+///
+/// Caches full `K` objects keyed by [`ObjectId`], matching the reflector's original,
+/// pre-[`Store`] behaviour.
+pub struct BTreeMapStore<K> {
+    data: Mutex<BTreeMap<ObjectId, K>>,
+}
+
+impl<K> Default for BTreeMapStore<K> {
+    fn default() -> Self {
+        BTreeMapStore {
+            data: Mutex::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl<K: Meta + Clone + Send + Sync> Store<K> for BTreeMapStore<K> {
+    type Output = K;
+
+    fn apply(&self, event: &WatchEvent<K>) -> Option<StoreEvent<K>> {
+        let mut data = self.data.lock().unwrap();
+        match event {
+            WatchEvent::Added(o) => {
+                let id = ObjectId::key_for(o);
+                if data.contains_key(&id) {
+                    None
+                } else {
+                    data.insert(id, o.clone());
+                    Some(StoreEvent::Added(o.clone()))
+                }
+            }
+            WatchEvent::Modified(o) => {
+                let id = ObjectId::key_for(o);
+                data.get_mut(&id).map(|e| {
+                    let old = e.clone();
+                    *e = o.clone();
+                    StoreEvent::Modified {
+                        old,
+                        new: o.clone(),
+                    }
+                })
+            }
+            WatchEvent::Deleted(o) => data.remove(&ObjectId::key_for(o)).map(StoreEvent::Deleted),
+            WatchEvent::Bookmark(_) | WatchEvent::Error(_) => None,
+        }
+    }
+
+    fn reset(&self, items: Vec<K>) {
+        let mut data = BTreeMap::new();
+        for i in items {
+            data.insert(ObjectId::key_for(&i), i);
+        }
+        *self.data.lock().unwrap() = data;
+    }
+
+    fn get(&self, id: &ObjectId) -> Option<K> {
+        self.data.lock().unwrap().get(id).cloned()
+    }
+
+    fn snapshot(&self) -> Vec<K> {
+        self.data.lock().unwrap().values().cloned().collect()
+    }
+}
+
+/// A projected/shadow [`Store`] that only retains a cheap derived value for each `K`
+///
+/// `project` runs once per Add/Modify, so e.g. a reflector over `Pod` can keep only
+/// `(name, phase, nodeName)` cached instead of the full object.
+pub struct ShadowStore<K, T, F> {
+    data: Mutex<BTreeMap<ObjectId, T>>,
+    project: F,
+    _marker: PhantomData<K>,
+}
+
+impl<K, T, F> ShadowStore<K, T, F>
+where
+    F: Fn(&K) -> T,
+{
+    /// Create a shadow store that projects every cached `K` down to a `T` via `project`
+    pub fn new(project: F) -> Self {
+        ShadowStore {
+            data: Mutex::new(BTreeMap::new()),
+            project,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K, T, F> Store<K> for ShadowStore<K, T, F>
+where
+    K: Meta,
+    T: Clone + Send + Sync,
+    F: Fn(&K) -> T + Send + Sync,
+{
+    type Output = T;
+
+    fn apply(&self, event: &WatchEvent<K>) -> Option<StoreEvent<T>> {
+        let mut data = self.data.lock().unwrap();
+        match event {
+            WatchEvent::Added(o) => {
+                let id = ObjectId::key_for(o);
+                if data.contains_key(&id) {
+                    None
+                } else {
+                    let projected = (self.project)(o);
+                    data.insert(id, projected.clone());
+                    Some(StoreEvent::Added(projected))
+                }
+            }
+            WatchEvent::Modified(o) => {
+                let id = ObjectId::key_for(o);
+                data.get_mut(&id).map(|e| {
+                    let old = e.clone();
+                    let new = (self.project)(o);
+                    *e = new.clone();
+                    StoreEvent::Modified { old, new }
+                })
+            }
+            WatchEvent::Deleted(o) => data.remove(&ObjectId::key_for(o)).map(StoreEvent::Deleted),
+            WatchEvent::Bookmark(_) | WatchEvent::Error(_) => None,
+        }
+    }
+
+    fn reset(&self, items: Vec<K>) {
+        let mut data = BTreeMap::new();
+        for i in items {
+            let projected = (self.project)(&i);
+            data.insert(ObjectId::key_for(&i), projected);
+        }
+        *self.data.lock().unwrap() = data;
+    }
+
+    fn get(&self, id: &ObjectId) -> Option<T> {
+        self.data.lock().unwrap().get(id).cloned()
+    }
+
+    fn snapshot(&self) -> Vec<T> {
+        self.data.lock().unwrap().values().cloned().collect()
+    }
+}
+
+/// A single change observed by a [`Reflector`] as it applies watch events to its [`Store`]
+///
+/// Returned by [`Reflector::subscribe`] so consumers can react to individual deltas
+/// instead of re-reading [`Reflector::state`] and diffing it themselves.
+#[derive(Clone)]
+pub enum StoreEvent<T> {
+    /// A new entry was added to the store
+    Added(T),
+    /// An existing entry was replaced
+    Modified {
+        /// The entry's value before this change
+        old: T,
+        /// The entry's value after this change
+        new: T,
+    },
+    /// An entry was removed from the store
+    Deleted(T),
+    /// Sent when [`Reflector::reset`] rebuilds the cache from a fresh list
+    Restarted(Vec<T>),
+}
+
+/// A subscription to a [`Reflector`]'s [`StoreEvent`]s, created by [`Reflector::subscribe`]
+///
+/// Iterating blocks for the next change; it ends once the reflector it was subscribed to,
+/// and every clone of that reflector, is dropped. This crate is synchronous end-to-end (see [`Reflector::poll`]), so this is
+/// a blocking multi-consumer broadcast built on [`std::sync::mpsc`] rather than a
+/// `futures::Stream` - each [`Reflector::subscribe`] call registers a fresh channel that
+/// gets every [`StoreEvent`] broadcast from then on.
+pub struct Subscription<T>(Receiver<StoreEvent<T>>);
+
+impl<T> Iterator for Subscription<T> {
+    type Item = StoreEvent<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.recv().ok()
     }
 }
 
@@ -185,16 +757,16 @@ where
 ///
 /// This is an internal subset of ['k8s_openapi::api::core::v1::ObjectReference']
 #[derive(Ord, PartialOrd, Hash, Eq, PartialEq, Clone)]
-struct ObjectId {
+pub struct ObjectId {
     name: String,
     namespace: Option<String>,
 }
 
-impl ToString for ObjectId {
-    fn to_string(&self) -> String {
+impl fmt::Display for ObjectId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.namespace {
-            Some(ns) => format!("{} [{}]", self.name, ns),
-            None => self.name.clone(),
+            Some(ns) => write!(f, "{} [{}]", self.name, ns),
+            None => write!(f, "{}", self.name),
         }
     }
 }
@@ -206,23 +778,12 @@ impl ObjectId {
             namespace: Meta::namespace(o),
         }
     }
-}
-
-/// Internal shared state of Reflector
-///
-/// Can remove this in k8s >= 1.16 once this uses Informer
-struct State<K> {
-    data: Cache<K>,
-    version: String,
-}
 
-impl<K> Default for State<K> {
-    fn default() -> Self {
-        State {
-            data: Default::default(),
-            version: 0.to_string(),
+    /// As [`ObjectId::key_for`], but for a bare [`ObjectMeta`] rather than a full `K`
+    fn from_meta(meta: &ObjectMeta) -> Self {
+        ObjectId {
+            name: meta.name.clone().unwrap_or_default(),
+            namespace: meta.namespace.clone(),
         }
     }
 }
-/// Internal representation for Reflector
-type Cache<K> = BTreeMap<ObjectId, K>;